@@ -1,8 +1,14 @@
 use clap::Arg;
+use parking_lot::Mutex;
 use rayon::prelude::*;
 use std::{
+    collections::VecDeque,
     error::Error,
+    fmt,
+    fs::File,
+    io::Read,
     ops::{Index, IndexMut},
+    sync::atomic::{AtomicBool, AtomicU8, Ordering},
 };
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -12,8 +18,8 @@ fn main() -> Result<(), Box<dyn Error>> {
         .args(&[
             Arg::with_name("SIDE")
                 .index(1)
-                .help("Lattice side length")
-                .required(true),
+                .help("Lattice side length (ignored when --input is given)")
+                .required(false),
             Arg::with_name("resolution")
                 .short("r")
                 .long("resolution")
@@ -26,11 +32,66 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .help("Statistical sample size")
                 .required(false)
                 .takes_value(true),
+            Arg::with_name("input")
+                .long("input")
+                .help("Load a single lattice from a file instead of sampling random ones")
+                .required(false)
+                .takes_value(true),
+            Arg::with_name("dump")
+                .long("dump")
+                .help("Print the final burnt lattice (only with --input)")
+                .required(false)
+                .takes_value(false),
+            Arg::with_name("threads")
+                .long("threads")
+                .help("Threads used to burn a single large lattice in parallel (only with --input)")
+                .required(false)
+                .takes_value(true),
         ])
         .get_matches();
 
+    // A single, reproducible lattice loaded from disk bypasses the Monte
+    // Carlo sampling below entirely.
+    if let Some(path) = matches.value_of("input") {
+        let file = File::open(path)?;
+        let mut lattice = Lattice::parse(file)?;
+
+        if lattice.side == 0 {
+            eprintln!("You must set a non-zero lattice");
+            return Ok(());
+        }
+
+        let threads: usize = if let Some(val) = matches.value_of("threads") {
+            val.parse()?
+        } else {
+            rayon::current_num_threads()
+        };
+
+        let (percolates, largest_cluster) = lattice.percolation();
+        let sweeps = lattice.burn_to_completion_parallel(threads);
+
+        println!(
+            "{}\t{}\t{:.5}",
+            sweeps,
+            percolates,
+            largest_cluster as f64 / (lattice.side * lattice.side) as f64
+        );
+
+        if matches.is_present("dump") {
+            print!("{}", lattice.dump());
+        }
+
+        return Ok(());
+    }
+
     // Load arguments
-    let n: usize = matches.value_of("SIDE").unwrap().parse()?;
+    let n: usize = match matches.value_of("SIDE") {
+        Some(val) => val.parse()?,
+        None => {
+            eprintln!("SIDE is required unless --input is given");
+            return Ok(());
+        }
+    };
     let resolution: usize = if let Some(val) = matches.value_of("resolution") {
         val.parse()?
     } else {
@@ -54,35 +115,46 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
 
     // Run for each probability point parallelly
-    let result: Vec<(f64, f64)> = (0..=resolution)
+    let result: Vec<(f64, f64, f64, f64)> = (0..=resolution)
         .into_par_iter()
         .map(|pidx| {
             // Calculate equidistant prob. points
             let p = pidx as f64 / (resolution as f64);
 
+            // Parallelly calculate independent samples
+            let (sweeps, percolating, largest_cluster_fraction) = (0..sample_size)
+                .into_par_iter()
+                .map(|_| {
+                    let mut lattice = Lattice::generate(n, p);
+                    let (percolates, largest_cluster) = lattice.percolation();
+                    let sweeps = lattice.burn_to_completion() as f64;
+
+                    (
+                        sweeps,
+                        if percolates { 1.0 } else { 0.0 },
+                        largest_cluster as f64 / (n * n) as f64,
+                    )
+                })
+                .reduce(
+                    || (0.0, 0.0, 0.0),
+                    |a, b| (a.0 + b.0, a.1 + b.1, a.2 + b.2),
+                );
+
             (
                 p,
-                // Parallelly calculate independent samples
-                (0..sample_size)
-                    .into_par_iter()
-                    .map(|_| {
-                        let mut lattice = Lattice::generate(n, p);
-                        let mut sweeps = 0;
-
-                        while let SweepResult::Ignited = lattice.sweep() {
-                            sweeps += 1;
-                        }
-
-                        sweeps as f64
-                    })
-                    .sum::<f64>()
-                    / (sample_size as f64))
+                sweeps / (sample_size as f64),
+                percolating / (sample_size as f64),
+                largest_cluster_fraction / (sample_size as f64),
+            )
         })
         .collect();
 
     // Print the result to `stdout`
-    for (p, t) in result {
-        println!("{:.4}\t{:.5}", p, t);
+    for (p, t, percolation_probability, largest_cluster_fraction) in result {
+        println!(
+            "{:.4}\t{:.5}\t{:.5}\t{:.5}",
+            p, t, percolation_probability, largest_cluster_fraction
+        );
     }
 
     Ok(())
@@ -96,71 +168,426 @@ enum LatticePoint {
     Burning,
 }
 
-/// Did the sweep result in a new burning tree?
-enum SweepResult {
-    /// No new burning tree
-    Identity,
-    /// One or more trees were ignited
-    Ignited,
+impl LatticePoint {
+    const EMPTY_CODE: u8 = 0;
+    const TREE_CODE: u8 = 1;
+    const BURNING_CODE: u8 = 2;
+
+    /// Encode as a `u8` for storage in an `AtomicU8`, used by
+    /// [`Lattice::burn_to_completion_parallel`].
+    fn to_atomic_code(self) -> u8 {
+        match self {
+            LatticePoint::Empty => Self::EMPTY_CODE,
+            LatticePoint::Tree => Self::TREE_CODE,
+            LatticePoint::Burning => Self::BURNING_CODE,
+        }
+    }
+
+    fn from_atomic_code(code: u8) -> Self {
+        match code {
+            Self::TREE_CODE => LatticePoint::Tree,
+            Self::BURNING_CODE => LatticePoint::Burning,
+            _ => LatticePoint::Empty,
+        }
+    }
 }
 
 /// A structure defining the lattice
 struct Lattice {
     side: usize,
-    current: Box<[LatticePoint]>,
+    grid: Matrix<LatticePoint>,
 }
 
 impl Lattice {
     /// Generate a new lattice of size `n*n`
-    /// 
+    ///
     /// `n` - the size of a side
-    /// 
+    ///
     /// `p` - the occupation probability
     pub fn generate(n: usize, p: f64) -> Self {
-        let field = (0..(n * n))
-            .map(|i| {
-                if rand::random::<f64>() < p {
-                    if i < n {
-                        // Ignite the first row
-                        LatticePoint::Burning
+        let mut grid = Matrix::with_bounds((0, 0), (n as isize, n as isize), LatticePoint::Empty);
+
+        for (i, j) in grid.cell_indices() {
+            if rand::random::<f64>() < p {
+                // Ignite the first row
+                grid[(i, j)] = if i == 0 {
+                    LatticePoint::Burning
+                } else {
+                    LatticePoint::Tree
+                };
+            }
+        }
+
+        Self { side: n, grid }
+    }
+
+    /// Parse a lattice from a text stream: the first whitespace-separated
+    /// token is the side length `n`, followed by `n*n` cell characters
+    /// (`.` empty, `T` tree, `*` burning) in row-major order; whitespace
+    /// between them, such as a line break after each row, is ignored.
+    pub fn parse(mut reader: impl Read) -> Result<Self, LatticeParseError> {
+        let mut contents = String::new();
+        reader
+            .read_to_string(&mut contents)
+            .map_err(LatticeParseError::Io)?;
+
+        let mut tokens = contents.split_whitespace();
+
+        let side: usize = tokens
+            .next()
+            .ok_or(LatticeParseError::MissingSide)?
+            .parse()
+            .map_err(LatticeParseError::InvalidSide)?;
+
+        let mut cells = tokens.flat_map(str::chars);
+
+        let mut grid = Matrix::with_bounds((0, 0), (side as isize, side as isize), LatticePoint::Empty);
+
+        for (i, j) in grid.cell_indices() {
+            let token = cells.next().ok_or(LatticeParseError::Truncated)?;
+
+            grid[(i, j)] = match token {
+                '.' => LatticePoint::Empty,
+                'T' => LatticePoint::Tree,
+                '*' => LatticePoint::Burning,
+                other => return Err(LatticeParseError::InvalidToken(other)),
+            };
+        }
+
+        Ok(Self { side, grid })
+    }
+
+    /// Render the lattice using the same token format accepted by
+    /// [`Lattice::parse`].
+    pub fn dump(&self) -> String {
+        let mut out = format!("{}\n", self.side);
+
+        for i in 0..self.side {
+            for j in 0..self.side {
+                out.push(match self[(i, j)] {
+                    LatticePoint::Empty => '.',
+                    LatticePoint::Tree => 'T',
+                    LatticePoint::Burning => '*',
+                });
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Burn the lattice to completion in a single pass.
+    ///
+    /// Reproduces the sweep count of the old in-place rescan loop: igniting
+    /// a neighbor below or to the right was free within a rescan, but a
+    /// neighbor above or to the left only ignited on the next one, and the
+    /// first rescan ignited every neighbor of an initially burning cell. This
+    /// floods out from every burning cell with a 0-1 BFS using those same
+    /// weights, igniting each tree when it is popped at its cheapest cost, so
+    /// the deepest cost reached matches the old rescan count.
+    pub fn burn_to_completion(&mut self) -> usize {
+        let mut frontier: VecDeque<((isize, isize), usize)> = self
+            .grid
+            .cell_indices()
+            .filter(|&coord| matches!(self.grid[coord], LatticePoint::Burning))
+            .map(|coord| (coord, 0))
+            .collect();
+
+        let mut max_cost = 0;
+        let mut ignited_any = false;
+
+        while let Some(((i, j), cost)) = frontier.pop_front() {
+            match self.grid[(i, j)] {
+                LatticePoint::Tree => self.grid[(i, j)] = LatticePoint::Burning,
+                LatticePoint::Burning if cost == 0 => {}
+                _ => continue,
+            }
+            max_cost = max_cost.max(cost);
+
+            for offset in [(1, 0), (0, 1)] {
+                let neighbor = (i + offset.0, j + offset.1);
+                if matches!(self.grid.view_translated(offset)[(i, j)], LatticePoint::Tree) {
+                    if cost == 0 {
+                        frontier.push_back((neighbor, 1));
                     } else {
-                        LatticePoint::Tree
+                        frontier.push_front((neighbor, cost));
                     }
-                } else {
-                    LatticePoint::Empty
+                    ignited_any = true;
                 }
-            })
-            .collect();
+            }
 
-        Self {
-            side: n,
-            current: field,
+            for offset in [(-1, 0), (0, -1)] {
+                let neighbor = (i + offset.0, j + offset.1);
+                if matches!(self.grid.view_translated(offset)[(i, j)], LatticePoint::Tree) {
+                    frontier.push_back((neighbor, cost + 1));
+                    ignited_any = true;
+                }
+            }
+        }
+
+        if ignited_any {
+            max_cost.max(1)
+        } else {
+            0
         }
     }
 
-    /// Perform a sweep
-    pub fn sweep(&mut self) -> SweepResult {
-        let mut result = SweepResult::Identity;
+    /// Lattice side length below which [`Lattice::burn_to_completion_parallel`]
+    /// just falls back to the serial frontier walk, since a lattice this
+    /// small doesn't have enough cells per frontier layer to offset the
+    /// cost of spinning up sharded parallel work.
+    const PARALLEL_THRESHOLD: usize = 512;
+
+    /// Burn the lattice to completion the same way as
+    /// [`Lattice::burn_to_completion`], but parallelize within the frontier
+    /// itself instead of only across independent lattices. Intended for the
+    /// single-huge-lattice regime (e.g. `10^4 x 10^4`) where the Monte Carlo
+    /// sampling loop in `main` has no other lattices to parallelize across.
+    ///
+    /// Cells are stored as `AtomicU8` so each thread can flip a neighboring
+    /// `Tree` to `Burning` with a compare-exchange instead of needing a
+    /// global lock; newly ignited cells are collected into `parking_lot`
+    /// mutex-guarded shards (one per worker thread) to keep contention on
+    /// any single shard low. Each round floods free (down/right) neighbors
+    /// to a fixed point before moving on, mirroring how the serial 0-1 BFS
+    /// keeps same-cost cells at the front of its frontier, so only costly
+    /// (up/left) neighbors push the round counter forward.
+    pub fn burn_to_completion_parallel(&mut self, threads: usize) -> usize {
+        self.burn_to_completion_parallel_with_threshold(threads, Self::PARALLEL_THRESHOLD)
+    }
+
+    fn burn_to_completion_parallel_with_threshold(
+        &mut self,
+        threads: usize,
+        threshold: usize,
+    ) -> usize {
+        if self.side < threshold {
+            return self.burn_to_completion();
+        }
+
+        let side = self.side;
+
+        let cells: Vec<AtomicU8> = (0..side * side)
+            .map(|idx| AtomicU8::new(self[(idx / side, idx % side)].to_atomic_code()))
+            .collect();
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("failed to build the parallel burn thread pool");
+
+        let initial_frontier: Vec<(usize, usize)> = (0..side * side)
+            .filter(|&idx| cells[idx].load(Ordering::Relaxed) == LatticePoint::BURNING_CODE)
+            .map(|idx| (idx / side, idx % side))
+            .collect();
+
+        let ignited_any = AtomicBool::new(false);
+
+        let max_depth = pool.install(|| {
+            let mut round = initial_frontier;
+            let mut depth = 0;
+            let mut max_depth = 0;
+
+            let try_ignite = |shards: &[Mutex<Vec<(usize, usize)>>], shard_count, ni, nj| {
+                let index = ni * side + nj;
+                if cells[index]
+                    .compare_exchange(
+                        LatticePoint::TREE_CODE,
+                        LatticePoint::BURNING_CODE,
+                        Ordering::AcqRel,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+                {
+                    ignited_any.store(true, Ordering::Relaxed);
+                    shards[index % shard_count].lock().push((ni, nj));
+                }
+            };
+
+            while !round.is_empty() {
+                max_depth = max_depth.max(depth);
+
+                // Keep flooding free neighbors within this round until
+                // nothing new ignites; every costly neighbor discovered
+                // along the way is deferred to the next round.
+                let mut current = round;
+                let mut next_round = Vec::new();
+
+                while !current.is_empty() {
+                    let shard_count = rayon::current_num_threads().max(1);
+                    let free_shards: Vec<Mutex<Vec<(usize, usize)>>> =
+                        (0..shard_count).map(|_| Mutex::new(Vec::new())).collect();
+                    let costly_shards: Vec<Mutex<Vec<(usize, usize)>>> =
+                        (0..shard_count).map(|_| Mutex::new(Vec::new())).collect();
+
+                    current.into_par_iter().for_each(|(i, j)| {
+                        if i < side - 1 {
+                            try_ignite(&free_shards, shard_count, i + 1, j);
+                        }
+                        if j < side - 1 {
+                            try_ignite(&free_shards, shard_count, i, j + 1);
+                        }
+                        if i > 0 {
+                            try_ignite(&costly_shards, shard_count, i - 1, j);
+                        }
+                        if j > 0 {
+                            try_ignite(&costly_shards, shard_count, i, j - 1);
+                        }
+                    });
+
+                    next_round.extend(costly_shards.into_iter().flat_map(|s| s.into_inner()));
+                    current = free_shards.into_iter().flat_map(|s| s.into_inner()).collect();
+                }
+
+                round = next_round;
+                depth += 1;
+            }
+
+            max_depth
+        });
+
+        for idx in 0..side * side {
+            self[(idx / side, idx % side)] =
+                LatticePoint::from_atomic_code(cells[idx].load(Ordering::Relaxed));
+        }
+
+        if ignited_any.load(Ordering::Relaxed) {
+            max_depth.max(1)
+        } else {
+            0
+        }
+    }
 
+    /// Site-percolation analysis of the occupied (`Tree`/`Burning`) cells.
+    ///
+    /// Returns whether the occupied sites form a cluster spanning the top
+    /// row to the bottom row, and the size of the largest occupied cluster,
+    /// excluding the virtual top/bottom sentinel nodes used to detect
+    /// spanning. This only depends on occupancy, not on burn state, so it
+    /// can be computed right after [`Lattice::generate`].
+    pub fn percolation(&self) -> (bool, usize) {
         let side = self.side;
+        let top = side * side;
+        let bottom = side * side + 1;
 
-        for i in 0..side { // Row
-            for j in 0..side { // Col
-                if let LatticePoint::Tree = self[(i, j)] {
-                    let should_burn = (i > 0 && matches!(self[(i - 1, j)], LatticePoint::Burning))
-                        || (i < side - 1 && matches!(self[(i + 1, j)], LatticePoint::Burning))
-                        || (j > 0 && matches!(self[(i, j - 1)], LatticePoint::Burning))
-                        || (j < side - 1 && matches!(self[(i, j + 1)], LatticePoint::Burning));
-
-                    if should_burn {
-                        self[(i, j)] = LatticePoint::Burning;
-                        result = SweepResult::Ignited;
+        let mut dsu = DisjointSet::new(side * side + 2);
+
+        for i in 0..side {
+            for j in 0..side {
+                if !self.is_occupied(i, j) {
+                    continue;
+                }
+
+                let cell = i * side + j;
+
+                if i == 0 {
+                    dsu.union(top, cell);
+                }
+                if i == side - 1 {
+                    dsu.union(bottom, cell);
+                }
+                if j + 1 < side && self.is_occupied(i, j + 1) {
+                    dsu.union(cell, i * side + j + 1);
+                }
+                if i + 1 < side && self.is_occupied(i + 1, j) {
+                    dsu.union(cell, (i + 1) * side + j);
+                }
+            }
+        }
+
+        let top_root = dsu.find(top);
+        let bottom_root = dsu.find(bottom);
+        let percolates = top_root == bottom_root;
+
+        let mut largest_cluster = 0;
+        for i in 0..side {
+            for j in 0..side {
+                if self.is_occupied(i, j) {
+                    let root = dsu.find(i * side + j);
+                    let mut real_size = dsu.size[root];
+                    if root == top_root {
+                        real_size -= 1;
                     }
+                    if root == bottom_root {
+                        real_size -= 1;
+                    }
+                    largest_cluster = largest_cluster.max(real_size);
                 }
             }
         }
 
-        result
+        (percolates, largest_cluster)
+    }
+
+    fn is_occupied(&self, row: usize, col: usize) -> bool {
+        matches!(self[(row, col)], LatticePoint::Tree | LatticePoint::Burning)
+    }
+}
+
+/// Error produced by [`Lattice::parse`] when the input stream is malformed.
+#[derive(Debug)]
+enum LatticeParseError {
+    Io(std::io::Error),
+    MissingSide,
+    InvalidSide(std::num::ParseIntError),
+    InvalidToken(char),
+    Truncated,
+}
+
+impl fmt::Display for LatticeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LatticeParseError::Io(e) => write!(f, "failed to read lattice input: {e}"),
+            LatticeParseError::MissingSide => write!(f, "missing lattice side length"),
+            LatticeParseError::InvalidSide(e) => write!(f, "invalid lattice side length: {e}"),
+            LatticeParseError::InvalidToken(c) => write!(f, "unexpected lattice token '{c}'"),
+            LatticeParseError::Truncated => {
+                write!(f, "input ended before the lattice was fully read")
+            }
+        }
+    }
+}
+
+impl Error for LatticeParseError {}
+
+/// A weighted quick-union disjoint-set with path halving, used to determine
+/// percolating clusters.
+struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    fn find(&mut self, mut node: usize) -> usize {
+        while self.parent[node] != node {
+            self.parent[node] = self.parent[self.parent[node]];
+            node = self.parent[node];
+        }
+        node
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+
+        if root_a == root_b {
+            return;
+        }
+
+        if self.size[root_a] < self.size[root_b] {
+            self.parent[root_a] = root_b;
+            self.size[root_b] += self.size[root_a];
+        } else {
+            self.parent[root_b] = root_a;
+            self.size[root_a] += self.size[root_b];
+        }
     }
 }
 
@@ -168,12 +595,188 @@ impl Index<(usize, usize)> for Lattice {
     type Output = LatticePoint;
 
     fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
-        &self.current[row * self.side + col]
+        &self.grid[(row as isize, col as isize)]
     }
 }
 
 impl IndexMut<(usize, usize)> for Lattice {
     fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
-        &mut self.current[row * self.side + col]
+        &mut self.grid[(row as isize, col as isize)]
+    }
+}
+
+/// A 2D grid addressed by explicit lower (inclusive) and upper (exclusive)
+/// bounds. Reads outside those bounds return a fixed `fill` value instead
+/// of panicking.
+struct Matrix<T> {
+    lower: (isize, isize),
+    upper: (isize, isize),
+    fill: T,
+    data: Box<[T]>,
+}
+
+impl<T> Matrix<T> {
+    /// Iterate over every coordinate within `[lower, upper)`, row-major.
+    pub fn cell_indices(&self) -> impl Iterator<Item = (isize, isize)> {
+        let (r0, c0) = self.lower;
+        let (r1, c1) = self.upper;
+
+        (r0..r1).flat_map(move |r| (c0..c1).map(move |c| (r, c)))
+    }
+
+    /// Obtain a view of this matrix whose coordinates are offset by
+    /// `(dr, dc)`, without copying the underlying storage.
+    pub fn view_translated(&self, offset: (isize, isize)) -> MatrixView<'_, T> {
+        MatrixView {
+            matrix: self,
+            offset,
+        }
+    }
+
+    fn contains(&self, (row, col): (isize, isize)) -> bool {
+        row >= self.lower.0 && row < self.upper.0 && col >= self.lower.1 && col < self.upper.1
+    }
+
+    fn offset(&self, (row, col): (isize, isize)) -> usize {
+        let cols = (self.upper.1 - self.lower.1) as usize;
+        (row - self.lower.0) as usize * cols + (col - self.lower.1) as usize
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Construct a matrix spanning `[lower, upper)`, with every cell
+    /// initialized to `fill`.
+    pub fn with_bounds(lower: (isize, isize), upper: (isize, isize), fill: T) -> Self {
+        let rows = (upper.0 - lower.0).max(0) as usize;
+        let cols = (upper.1 - lower.1).max(0) as usize;
+
+        Self {
+            lower,
+            upper,
+            data: vec![fill.clone(); rows * cols].into_boxed_slice(),
+            fill,
+        }
+    }
+}
+
+impl<T> Index<(isize, isize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, coord: (isize, isize)) -> &Self::Output {
+        if self.contains(coord) {
+            &self.data[self.offset(coord)]
+        } else {
+            &self.fill
+        }
+    }
+}
+
+impl<T> IndexMut<(isize, isize)> for Matrix<T> {
+    fn index_mut(&mut self, coord: (isize, isize)) -> &mut Self::Output {
+        assert!(self.contains(coord), "cannot write to an out-of-bounds cell");
+
+        let offset = self.offset(coord);
+        &mut self.data[offset]
+    }
+}
+
+/// A read-only view into a [`Matrix`] whose coordinates are translated by a
+/// fixed offset, e.g. to read a neighboring cell without recomputing the
+/// offset at every call site.
+struct MatrixView<'a, T> {
+    matrix: &'a Matrix<T>,
+    offset: (isize, isize),
+}
+
+impl<T> Index<(isize, isize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (isize, isize)) -> &Self::Output {
+        &self.matrix[(row + self.offset.0, col + self.offset.1)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn burn_to_completion_dense_grid_with_burning_first_row_takes_one_sweep() {
+        let mut lattice = Lattice::parse("3\n***\nTTT\nTTT\n".as_bytes()).unwrap();
+        assert_eq!(lattice.burn_to_completion(), 1);
+    }
+
+    #[test]
+    fn burn_to_completion_reproduces_the_anisotropic_rescan_count() {
+        // Burning only ignites in the bottom-right corner, so reaching the
+        // opposite corner needs four up/left hops, one per rescan.
+        let mut lattice = Lattice::parse("3\nTTT\nTTT\nTT*\n".as_bytes()).unwrap();
+        assert_eq!(lattice.burn_to_completion(), 4);
+    }
+
+    #[test]
+    fn burn_to_completion_reports_zero_when_nothing_ignites() {
+        let mut lattice = Lattice::parse("2\n*.\n..\n".as_bytes()).unwrap();
+        assert_eq!(lattice.burn_to_completion(), 0);
+    }
+
+    #[test]
+    fn burn_to_completion_parallel_matches_serial_on_the_anisotropic_fixture() {
+        // Force the parallel path with threshold 0 so a tiny lattice still
+        // exercises it, and check it against the known-good serial result.
+        let input = "3\nTTT\nTTT\nTT*\n";
+        let mut serial = Lattice::parse(input.as_bytes()).unwrap();
+        let mut parallel = Lattice::parse(input.as_bytes()).unwrap();
+
+        assert_eq!(
+            parallel.burn_to_completion_parallel_with_threshold(2, 0),
+            serial.burn_to_completion(),
+        );
+    }
+
+    #[test]
+    fn burn_to_completion_parallel_matches_serial_on_a_dense_first_row() {
+        // Regression test: a flat per-round depth counter reports one
+        // "sweep" per row here instead of the single sweep a dense,
+        // fully-burning first row actually takes.
+        let input = "5\n*****\nTTTTT\nTTTTT\nTTTTT\nTTTTT\n";
+        let mut serial = Lattice::parse(input.as_bytes()).unwrap();
+        let mut parallel = Lattice::parse(input.as_bytes()).unwrap();
+
+        let parallel_result = parallel.burn_to_completion_parallel_with_threshold(2, 0);
+        assert_eq!(parallel_result, serial.burn_to_completion());
+        assert_eq!(parallel_result, 1);
+    }
+
+    #[test]
+    fn percolation_excludes_sentinels_from_the_largest_cluster_size() {
+        // A single fully-occupied cell touches both the top and bottom
+        // sentinel nodes at once; its real cluster size is still 1.
+        let lattice = Lattice::parse("1\nT\n".as_bytes()).unwrap();
+        let (percolates, largest_cluster) = lattice.percolation();
+        assert!(percolates);
+        assert_eq!(largest_cluster, 1);
+    }
+
+    #[test]
+    fn percolation_reports_the_largest_of_several_disjoint_clusters() {
+        let lattice = Lattice::parse("3\nTT.\n..T\n.TT\n".as_bytes()).unwrap();
+        let (percolates, largest_cluster) = lattice.percolation();
+        assert!(!percolates);
+        assert_eq!(largest_cluster, 3);
+    }
+
+    #[test]
+    fn parse_and_dump_round_trip() {
+        let input = "3\nT.*\n.T.\n**T\n";
+        let lattice = Lattice::parse(input.as_bytes()).unwrap();
+        assert_eq!(lattice.dump(), input);
+    }
+
+    #[test]
+    fn parse_accepts_a_zero_side_lattice() {
+        let lattice = Lattice::parse("0\n".as_bytes()).unwrap();
+        assert_eq!(lattice.side, 0);
     }
 }
+